@@ -1,12 +1,18 @@
 use chrono::Utc;
 use clap::Parser;
 use eyre::Result;
+use futures::stream::{self, StreamExt};
 use std::path::PathBuf;
 use yt_sub::user_settings_cli::UserSettingsCLI;
-use yt_sub_core::{logger::Logger, UserSettings};
+use yt_sub_core::video::LiveStatus;
+use yt_sub_core::{downloader, http, logger::Logger, UserSettings};
 
 use crate::CONFIG_DESC;
 
+/// Live-status enrichment does one extra watch-page GET per video, so it's
+/// capped and opt-in rather than unconditional on every run.
+const LIVE_STATUS_ENRICHMENT_CONCURRENCY: usize = 4;
+
 #[derive(Debug, Parser)]
 pub struct RunArgs {
     #[arg(long, help = CONFIG_DESC)]
@@ -30,6 +36,7 @@ impl RunArgs {
         let logger = Logger::new(cron);
 
         let settings = UserSettings::read(config.as_ref())?;
+        let http_client = http::build_client(&settings.http)?;
 
         let last_run_at = if let Some(hours_offset) = hours_offset {
             Utc::now() - chrono::Duration::hours(hours_offset as i64)
@@ -40,7 +47,10 @@ impl RunArgs {
         let mut new_videos = vec![];
 
         for channel in &settings.channels {
-            match channel.get_fresh_videos(last_run_at).await {
+            match channel
+                .get_fresh_videos(last_run_at, &http_client, &settings.http, &logger)
+                .await
+            {
                 Ok(videos) => {
                     new_videos.extend(videos);
                 }
@@ -58,13 +68,51 @@ impl RunArgs {
         // Sort videos by publication date (newest first)
         new_videos.sort_by(|a, b| b.published_at.cmp(&a.published_at));
 
+        // `parse_rss` already flags most premieres/livestreams from their RSS
+        // `published_at`; only hit the watch page for videos it left `Published`,
+        // and only when the user opted in, since this is one extra GET per video.
+        if settings.enrich_live_status {
+            stream::iter(new_videos.iter_mut().filter(|video| video.live_status == LiveStatus::Published))
+                .for_each_concurrent(LIVE_STATUS_ENRICHMENT_CONCURRENCY, |video| async {
+                    if let Err(e) = video.enrich_live_status(&http_client).await {
+                        logger.error(&format!("Error: {e}"));
+                    }
+                })
+                .await;
+        }
+
+        if let Some(download_config) = &settings.downloader {
+            let excluded_handles: std::collections::HashSet<&str> = settings
+                .channels
+                .iter()
+                .filter(|channel| !channel.download_enabled)
+                .filter_map(|channel| channel.handle.as_deref())
+                .collect();
+
+            let downloadable_videos: Vec<_> = new_videos
+                .iter()
+                .filter(|video| {
+                    video
+                        .channel_handle
+                        .as_deref()
+                        .is_none_or(|handle| !excluded_handles.contains(handle))
+                })
+                .collect();
+
+            if let Err(e) =
+                downloader::download_fresh_videos(download_config, &downloadable_videos, &logger).await
+            {
+                logger.error(&format!("Error: {e}"));
+            }
+        }
+
         for notifier in &settings.notifiers {
             let notifications = new_videos
                 .iter()
                 .map(|video| video.notification_text(notifier))
                 .collect::<Vec<String>>();
 
-            match notifier.notify(notifications, cron).await {
+            match notifier.notify(&http_client, &new_videos, notifications, cron).await {
                 Ok(_) => {}
                 Err(e) => {
                     logger.error(&format!("Error: {e}"));
@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use eyre::Result;
+use serde::Deserialize;
+
+use crate::http::{self, HttpConfig};
+use crate::innertube;
+use crate::logger::Logger;
+use crate::video::Video;
+
+/// A subscribed YouTube channel. Fresh videos are fetched from the channel's
+/// RSS feed, retrying transient failures per `http_config` so a single slow
+/// or hung request doesn't stall the whole run. Channels that upload faster
+/// than the ~15-item RSS window can hold should set `use_innertube` so older
+/// fresh uploads aren't silently dropped.
+#[derive(Debug, Deserialize)]
+pub struct Channel {
+    pub channel_id: String,
+    pub handle: Option<String>,
+    #[serde(default)]
+    pub use_innertube: bool,
+    /// Whether this channel's fresh videos should be passed to the downloader
+    /// when `UserSettings::downloader` is configured. Lets a user enable
+    /// downloads globally but exclude specific channels.
+    #[serde(default = "default_download_enabled")]
+    pub download_enabled: bool,
+}
+
+fn default_download_enabled() -> bool {
+    true
+}
+
+impl Channel {
+    fn rss_url(&self) -> String {
+        format!(
+            "https://www.youtube.com/feeds/videos.xml?channel_id={}",
+            self.channel_id
+        )
+    }
+
+    pub async fn get_fresh_videos(
+        &self,
+        last_run_at: DateTime<Utc>,
+        client: &reqwest::Client,
+        http_config: &HttpConfig,
+        logger: &Logger,
+    ) -> Result<Vec<Video>> {
+        let rss_data = http::get_with_retry(client, &self.rss_url(), http_config)
+            .await?
+            .text()
+            .await?;
+
+        let mut videos = Video::parse_rss(rss_data, self.handle.clone())?;
+        videos.retain(|video| video.published_at >= last_run_at);
+
+        if self.use_innertube {
+            // Innertube is an unofficial, reverse-engineered endpoint that can
+            // start rejecting requests at any time (e.g. a stale API key), so a
+            // failure here should only cost the overflow it would have added,
+            // not the RSS videos already parsed above.
+            match innertube::fetch_videos_beyond_rss_window(
+                client,
+                &self.channel_id,
+                self.handle.as_deref(),
+                last_run_at,
+            )
+            .await
+            {
+                Ok(extra) => videos = merge_videos(videos, extra),
+                Err(e) => logger.error(&format!(
+                    "Innertube pagination failed for channel {}, falling back to RSS-only videos: {e}",
+                    self.channel_id
+                )),
+            }
+        }
+
+        Ok(videos)
+    }
+}
+
+/// Extends `rss_videos` with any `extra` videos (from Innertube pagination)
+/// not already present by link, so the RSS-sourced metadata wins for videos
+/// both sources found.
+fn merge_videos(mut rss_videos: Vec<Video>, extra: Vec<Video>) -> Vec<Video> {
+    let known_links: HashSet<String> = rss_videos.iter().map(|v| v.link.clone()).collect();
+    rss_videos.extend(extra.into_iter().filter(|v| !known_links.contains(&v.link)));
+    rss_videos
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::video::LiveStatus;
+
+    fn video(link: &str) -> Video {
+        Video {
+            channel: "Test Channel".to_string(),
+            channel_handle: Some("@TestChannel".to_string()),
+            title: link.to_string(),
+            link: link.to_string(),
+            published_at: Utc::now(),
+            scheduled_at: None,
+            live_status: LiveStatus::Published,
+            video_id: None,
+            description: None,
+            thumbnail_url: None,
+            view_count: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_videos_skips_links_already_known_from_rss() {
+        let rss_videos = vec![video("https://example.com/1")];
+        let extra = vec![video("https://example.com/1"), video("https://example.com/2")];
+
+        let merged = merge_videos(rss_videos, extra);
+        let links: Vec<_> = merged.iter().map(|v| v.link.as_str()).collect();
+
+        assert_eq!(links, vec!["https://example.com/1", "https://example.com/2"]);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use eyre::Result;
+use futures::stream::{self, StreamExt};
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::process::Command;
+
+use crate::logger::Logger;
+use crate::video::Video;
+
+#[derive(Debug, Deserialize)]
+pub struct DownloadConfig {
+    pub output_dir: PathBuf,
+    #[serde(default = "default_binary")]
+    pub binary: String,
+    #[serde(default = "default_output_template")]
+    pub output_template: String,
+    #[serde(default = "default_format")]
+    pub format: String,
+    #[serde(default)]
+    pub extra_args: Vec<String>,
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+}
+
+fn default_binary() -> String {
+    "yt-dlp".to_string()
+}
+
+fn default_output_template() -> String {
+    "%(title)s [%(id)s].%(ext)s".to_string()
+}
+
+fn default_format() -> String {
+    "bestvideo+bestaudio/best".to_string()
+}
+
+fn default_concurrency() -> usize {
+    2
+}
+
+#[derive(Debug, Error)]
+pub enum DownloadError {
+    #[error("failed to spawn `{binary}`: {source}")]
+    Spawn {
+        binary: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("`{binary}` exited with {status}: {stderr}")]
+    NonZeroExit {
+        binary: String,
+        status: std::process::ExitStatus,
+        stderr: String,
+    },
+}
+
+/// Downloads every video in `videos` via `yt-dlp` (or `youtube-dl`, depending on
+/// `config.binary`), skipping links whose video id is already present in
+/// `config.output_dir`, with up to `config.concurrency` downloads in flight.
+/// Individual failures are logged through `logger` rather than aborting the run.
+///
+/// Takes `&[&Video]` rather than `&[Video]` so callers can pass a subset (e.g.
+/// videos from channels that opted into downloads) without cloning.
+pub async fn download_fresh_videos(config: &DownloadConfig, videos: &[&Video], logger: &Logger) -> Result<()> {
+    let already_downloaded = existing_video_ids(&config.output_dir)?;
+
+    let pending: Vec<&Video> = videos
+        .iter()
+        .copied()
+        .filter(|video| !already_downloaded.iter().any(|id| video.link.contains(id.as_str())))
+        .collect();
+
+    stream::iter(pending)
+        .for_each_concurrent(config.concurrency, |video| async move {
+            if let Err(e) = download_one(config, video).await {
+                logger.error(&format!("yt-dlp download failed for {}: {e}", video.link));
+            }
+        })
+        .await;
+
+    Ok(())
+}
+
+async fn download_one(config: &DownloadConfig, video: &Video) -> Result<(), DownloadError> {
+    let output = Command::new(&config.binary)
+        .arg("-f")
+        .arg(&config.format)
+        .arg("-o")
+        .arg(config.output_dir.join(&config.output_template))
+        .args(&config.extra_args)
+        .arg(&video.link)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .await
+        .map_err(|source| DownloadError::Spawn {
+            binary: config.binary.clone(),
+            source,
+        })?;
+
+    if !output.status.success() {
+        return Err(DownloadError::NonZeroExit {
+            binary: config.binary.clone(),
+            status: output.status,
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Extracts video ids already on disk from filenames matching the
+/// `[%(id)s]` marker in the default output template, so re-runs can skip them.
+fn existing_video_ids(output_dir: &Path) -> Result<HashSet<String>> {
+    let mut ids = HashSet::new();
+
+    if !output_dir.exists() {
+        return Ok(ids);
+    }
+
+    for entry in std::fs::read_dir(output_dir)? {
+        let name = entry?.file_name();
+        let Some(name) = name.to_str() else { continue };
+
+        if let Some(start) = name.rfind('[') {
+            if let Some(end) = name[start..].find(']') {
+                ids.insert(name[start + 1..start + end].to_string());
+            }
+        }
+    }
+
+    Ok(ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_existing_video_ids_extracts_bracketed_id_from_filenames() {
+        let dir = std::env::temp_dir().join(format!("yt_sub_test_downloads_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("Some Title [abc123].mp4"), b"").unwrap();
+        std::fs::write(dir.join("Untagged Title.mp4"), b"").unwrap();
+
+        let ids = existing_video_ids(&dir).unwrap();
+
+        assert_eq!(ids, HashSet::from(["abc123".to_string()]));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_existing_video_ids_returns_empty_set_for_missing_dir() {
+        let dir = std::env::temp_dir().join("yt_sub_test_downloads_does_not_exist");
+
+        let ids = existing_video_ids(&dir).unwrap();
+
+        assert!(ids.is_empty());
+    }
+}
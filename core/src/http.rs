@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use eyre::Result;
+use serde::Deserialize;
+
+/// Settings for the shared `reqwest::Client` used for all network calls
+/// (channel RSS fetches, live-status enrichment, Slack/Telegram notifications).
+///
+/// TLS backend selection (`default-tls` / `rustls-tls-webpki-roots` /
+/// `rustls-tls-native-roots`) is a build-time choice made via this crate's
+/// Cargo features, not a runtime field here.
+#[derive(Debug, Deserialize)]
+#[serde(default)]
+pub struct HttpConfig {
+    pub timeout_secs: u64,
+    pub max_retries: u32,
+    pub user_agent: String,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: 10,
+            max_retries: 3,
+            user_agent: format!("yt-sub-rs/{}", env!("CARGO_PKG_VERSION")),
+        }
+    }
+}
+
+pub fn build_client(config: &HttpConfig) -> Result<reqwest::Client> {
+    Ok(reqwest::Client::builder()
+        .timeout(Duration::from_secs(config.timeout_secs))
+        .user_agent(config.user_agent.clone())
+        .build()?)
+}
+
+/// Issues a GET request, retrying transient 5xx responses and timeouts with
+/// bounded exponential backoff (1s, 2s, 4s, ...) up to `config.max_retries` times.
+pub async fn get_with_retry(client: &reqwest::Client, url: &str, config: &HttpConfig) -> Result<reqwest::Response> {
+    let mut attempt = 0;
+
+    loop {
+        let result = client.get(url).send().await;
+
+        let is_retryable = match &result {
+            Ok(response) => response.status().is_server_error(),
+            Err(e) => e.is_timeout() || e.is_connect(),
+        };
+
+        if is_retryable && attempt < config.max_retries {
+            attempt += 1;
+            tokio::time::sleep(Duration::from_secs(2u64.pow(attempt - 1))).await;
+            continue;
+        }
+
+        return Ok(result?);
+    }
+}
@@ -0,0 +1,341 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+
+use eyre::{eyre, Result};
+use rss::{ChannelBuilder, Guid, Item, ItemBuilder};
+use serde::Deserialize;
+
+use crate::video::Video;
+
+const TELEGRAM_MESSAGE_LIMIT: usize = 4096;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Notifier {
+    Log(),
+    Slack(SlackConfig),
+    Telegram(TelegramConfig),
+    Rss(RssConfig),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SlackConfig {
+    pub webhook_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TelegramConfig {
+    pub bot_token: String,
+    pub chat_id: String,
+    #[serde(default)]
+    pub disable_web_page_preview: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RssConfig {
+    pub feed_path: PathBuf,
+    pub title: String,
+    pub link: String,
+    #[serde(default = "default_max_items")]
+    pub max_items: usize,
+}
+
+fn default_max_items() -> usize {
+    100
+}
+
+impl Notifier {
+    pub async fn notify(&self, client: &reqwest::Client, videos: &[Video], notifications: Vec<String>, cron: bool) -> Result<()> {
+        if videos.is_empty() {
+            return Ok(());
+        }
+
+        match self {
+            Notifier::Log() => {
+                let _ = cron;
+                for notification in notifications {
+                    println!("{notification}");
+                }
+                Ok(())
+            }
+            Notifier::Slack(config) => {
+                let text = notifications.join("\n");
+                let attachments = slack_attachments(videos);
+
+                let mut payload = serde_json::json!({ "text": text });
+                if !attachments.is_empty() {
+                    payload["attachments"] = serde_json::json!(attachments);
+                }
+
+                client
+                    .post(&config.webhook_url)
+                    .json(&payload)
+                    .send()
+                    .await?
+                    .error_for_status()?;
+
+                Ok(())
+            }
+            Notifier::Telegram(config) => {
+                let url = format!("https://api.telegram.org/bot{}/sendMessage", config.bot_token);
+
+                for batch in batch_messages(&notifications, TELEGRAM_MESSAGE_LIMIT) {
+                    client
+                        .post(&url)
+                        .json(&serde_json::json!({
+                            "chat_id": config.chat_id,
+                            "text": batch,
+                            "parse_mode": "MarkdownV2",
+                            "disable_web_page_preview": config.disable_web_page_preview,
+                        }))
+                        .send()
+                        .await
+                        .and_then(|response| response.error_for_status())
+                        .map_err(|e| redact_telegram_error(e, &config.bot_token))?;
+                }
+
+                Ok(())
+            }
+            Notifier::Rss(config) => update_rss_feed(config, videos),
+        }
+    }
+}
+
+/// Merges freshly-found `videos` ahead of the existing items in the feed at
+/// `config.feed_path` (if any), caps the result at `config.max_items`, and
+/// writes the feed back atomically via a temp file + rename.
+fn update_rss_feed(config: &RssConfig, videos: &[Video]) -> Result<()> {
+    let mut items: Vec<Item> = videos
+        .iter()
+        .map(|video| {
+            ItemBuilder::default()
+                .title(Some(video.title.clone()))
+                .link(Some(video.link.clone()))
+                .author(video.channel_handle.clone())
+                .guid(Some(Guid {
+                    value: video.link.clone(),
+                    permalink: true,
+                }))
+                .pub_date(Some(video.published_at.to_rfc2822()))
+                .build()
+        })
+        .collect();
+
+    if config.feed_path.exists() {
+        let file = File::open(&config.feed_path)?;
+        let existing = rss::Channel::read_from(BufReader::new(file)).map_err(|e| eyre!(e))?;
+        items.extend(existing.items().iter().cloned());
+    }
+    items.truncate(config.max_items);
+
+    let channel = ChannelBuilder::default()
+        .title(config.title.clone())
+        .link(config.link.clone())
+        .description(format!("Newly discovered videos for {}", config.title))
+        .items(items)
+        .build();
+
+    let tmp_path = config.feed_path.with_extension("tmp");
+    let file = File::create(&tmp_path)?;
+    channel.write_to(BufWriter::new(file)).map_err(|e| eyre!(e))?;
+    std::fs::rename(&tmp_path, &config.feed_path)?;
+
+    Ok(())
+}
+
+/// Builds one Slack attachment per video that has a thumbnail or view count to
+/// show, so Slack renders a preview image and view count alongside the message.
+fn slack_attachments(videos: &[Video]) -> Vec<serde_json::Value> {
+    videos
+        .iter()
+        .filter(|video| video.thumbnail_url.is_some() || video.view_count.is_some())
+        .map(|video| {
+            let mut attachment = serde_json::json!({
+                "title": video.title,
+                "title_link": video.link,
+            });
+
+            if let Some(thumbnail_url) = &video.thumbnail_url {
+                attachment["image_url"] = serde_json::json!(thumbnail_url);
+            }
+            if let Some(view_count) = video.view_count {
+                attachment["text"] = serde_json::json!(format!("{view_count} views"));
+            }
+
+            attachment
+        })
+        .collect()
+}
+
+/// Groups notification lines into messages no longer than `limit` characters each,
+/// joining lines with newlines and starting a new batch whenever the next line
+/// wouldn't fit in the current one.
+fn batch_messages(lines: &[String], limit: usize) -> Vec<String> {
+    let mut batches = vec![];
+    let mut current = String::new();
+
+    for line in lines {
+        let needed_len = if current.is_empty() {
+            line.len()
+        } else {
+            current.len() + 1 + line.len()
+        };
+
+        if needed_len > limit && !current.is_empty() {
+            batches.push(std::mem::take(&mut current));
+        }
+
+        if !current.is_empty() {
+            current.push('\n');
+        }
+        current.push_str(line);
+    }
+
+    if !current.is_empty() {
+        batches.push(current);
+    }
+
+    batches
+}
+
+/// `reqwest::Error`'s `Display` includes the effective request URL, which for
+/// Telegram embeds the bot token (`.../bot<token>/sendMessage`). Scrub it
+/// before the error reaches `run.rs`'s `logger.error`, since cron logs are
+/// otherwise a straightforward way to leak a live bot token.
+fn redact_telegram_error(error: reqwest::Error, bot_token: &str) -> eyre::Report {
+    eyre!(redact_token(&error.to_string(), bot_token))
+}
+
+fn redact_token(message: &str, token: &str) -> String {
+    message.replace(token, "***")
+}
+
+/// Escapes Telegram MarkdownV2 reserved characters:
+/// `_ * [ ] ( ) ~ \` > # + - = | { } . !`
+pub fn escape_markdown_v2(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if matches!(
+            c,
+            '_' | '*'
+                | '['
+                | ']'
+                | '('
+                | ')'
+                | '~'
+                | '`'
+                | '>'
+                | '#'
+                | '+'
+                | '-'
+                | '='
+                | '|'
+                | '{'
+                | '}'
+                | '.'
+                | '!'
+                | '\\'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn video(title: &str, link: &str, published_at: &str) -> Video {
+        Video {
+            channel: "Test Channel".to_string(),
+            channel_handle: Some("@TestChannel".to_string()),
+            title: title.to_string(),
+            link: link.to_string(),
+            published_at: DateTime::parse_from_rfc3339(published_at)
+                .unwrap()
+                .with_timezone(&Utc),
+            scheduled_at: None,
+            live_status: crate::video::LiveStatus::Published,
+            video_id: None,
+            description: None,
+            thumbnail_url: None,
+            view_count: None,
+        }
+    }
+
+    #[test]
+    fn test_update_rss_feed_merges_new_items_ahead_of_old_and_caps_total() {
+        let feed_path =
+            std::env::temp_dir().join(format!("yt_sub_test_feed_{}.xml", std::process::id()));
+        let _ = std::fs::remove_file(&feed_path);
+
+        let config = RssConfig {
+            feed_path: feed_path.clone(),
+            title: "Test Feed".to_string(),
+            link: "https://example.com".to_string(),
+            max_items: 2,
+        };
+
+        update_rss_feed(
+            &config,
+            &[video("First", "https://example.com/1", "2024-01-01T10:00:00Z")],
+        )
+        .unwrap();
+        update_rss_feed(
+            &config,
+            &[video("Second", "https://example.com/2", "2024-01-02T10:00:00Z")],
+        )
+        .unwrap();
+
+        let channel =
+            rss::Channel::read_from(BufReader::new(File::open(&feed_path).unwrap())).unwrap();
+        let titles: Vec<_> = channel
+            .items()
+            .iter()
+            .map(|item| item.title().unwrap().to_string())
+            .collect();
+        assert_eq!(titles, vec!["Second", "First"]);
+
+        update_rss_feed(
+            &config,
+            &[video("Third", "https://example.com/3", "2024-01-03T10:00:00Z")],
+        )
+        .unwrap();
+
+        let channel =
+            rss::Channel::read_from(BufReader::new(File::open(&feed_path).unwrap())).unwrap();
+        assert_eq!(channel.items().len(), 2);
+
+        std::fs::remove_file(&feed_path).unwrap();
+    }
+
+    #[test]
+    fn test_slack_attachments_only_built_for_videos_with_metadata() {
+        let plain = video("Plain", "https://example.com/1", "2024-01-01T10:00:00Z");
+        let mut rich = video("Rich", "https://example.com/2", "2024-01-02T10:00:00Z");
+        rich.thumbnail_url = Some("https://example.com/thumb.jpg".to_string());
+        rich.view_count = Some(123);
+
+        let attachments = slack_attachments(&[plain, rich]);
+
+        assert_eq!(attachments.len(), 1);
+        assert_eq!(attachments[0]["title"], "Rich");
+        assert_eq!(attachments[0]["image_url"], "https://example.com/thumb.jpg");
+        assert_eq!(attachments[0]["text"], "123 views");
+    }
+
+    #[test]
+    fn test_redact_token_strips_bot_token_from_error_message() {
+        let message = "error sending request for url (https://api.telegram.org/bot123456:ABC-DEF/sendMessage)";
+        let redacted = redact_token(message, "123456:ABC-DEF");
+
+        assert!(!redacted.contains("123456:ABC-DEF"));
+        assert!(redacted.contains("***"));
+    }
+}
@@ -2,7 +2,16 @@ use chrono::{DateTime, Utc};
 use eyre::Result;
 use xmltojson::to_json;
 
-use crate::notifier::Notifier;
+use crate::notifier::{self, Notifier};
+
+/// Whether a video is already watchable, still a scheduled premiere/livestream,
+/// or currently streaming live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiveStatus {
+    Published,
+    Upcoming,
+    Live,
+}
 
 #[derive(Debug)]
 pub struct Video {
@@ -11,6 +20,12 @@ pub struct Video {
     pub title: String,
     pub link: String,
     pub published_at: DateTime<Utc>,
+    pub scheduled_at: Option<DateTime<Utc>>,
+    pub live_status: LiveStatus,
+    pub video_id: Option<String>,
+    pub description: Option<String>,
+    pub thumbnail_url: Option<String>,
+    pub view_count: Option<u64>,
 }
 
 impl Video {
@@ -32,12 +47,35 @@ impl Video {
                 published_at.parse().expect("Failed to parse DateTime");
             let link = video_data["link"]["@href"].as_str().unwrap();
 
+            let media_group = &video_data["media:group"];
+            let video_id = video_data["yt:videoId"].as_str().map(str::to_string);
+            let description = media_group["media:description"].as_str().map(str::to_string);
+            let thumbnail_url = media_group["media:thumbnail"]["@url"].as_str().map(str::to_string);
+            let view_count = media_group["media:community"]["media:statistics"]["@views"]
+                .as_str()
+                .and_then(|views| views.parse().ok());
+
+            // YouTube backdates a premiere/scheduled livestream's RSS `published`
+            // timestamp to its scheduled start time, so a future `published_at` is
+            // itself a reliable live-broadcast hint — no watch-page fetch needed.
+            let (live_status, scheduled_at) = if published_at > Utc::now() {
+                (LiveStatus::Upcoming, Some(published_at))
+            } else {
+                (LiveStatus::Published, None)
+            };
+
             let video = Video {
                 channel: channel.to_string(),
                 channel_handle: channel_handle.clone(),
                 title: title.to_string(),
                 link: link.to_string(),
                 published_at,
+                scheduled_at,
+                live_status,
+                video_id,
+                description,
+                thumbnail_url,
+                view_count,
             };
 
             videos.push(video);
@@ -46,8 +84,32 @@ impl Video {
         Ok(videos)
     }
 
+    /// Fetches the watch page and looks for the scheduled-start timestamp YouTube
+    /// embeds for premieres and scheduled livestreams ("Premieres in …" / "This
+    /// live event will begin in …"). `parse_rss` already flags most of these via
+    /// a future `published_at`; this is the opt-in fallback for the cases RSS
+    /// alone can't tell apart from a normal upload (e.g. the stream going live
+    /// mid-premiere). Callers should skip it for videos `parse_rss` already
+    /// classified as non-`Published`.
+    pub async fn enrich_live_status(&mut self, client: &reqwest::Client) -> Result<()> {
+        let html = client.get(&self.link).send().await?.text().await?;
+
+        if let Some(scheduled_at) = extract_scheduled_start(&html) {
+            self.scheduled_at = Some(scheduled_at);
+            self.live_status = if scheduled_at > Utc::now() {
+                LiveStatus::Upcoming
+            } else {
+                LiveStatus::Live
+            };
+        }
+
+        Ok(())
+    }
+
     pub fn notification_text(&self, notifier: &Notifier) -> String {
-        let time_ago = self.format_time_ago();
+        let time_ago = self
+            .format_live_status()
+            .unwrap_or_else(|| self.format_time_ago());
         let channel_handle = self.channel_handle.as_deref().unwrap_or("");
 
         match notifier {
@@ -70,7 +132,13 @@ impl Video {
                     parts.push(format!("- {}", time_ago));
                 }
 
-                parts.join(" ")
+                let mut text = parts.join(" ");
+
+                if let Some(description) = &self.description {
+                    text.push_str(&format!("\n  {}", truncate_description(description, 140)));
+                }
+
+                text
             }
             Notifier::Slack(_) => {
                 format!(
@@ -78,9 +146,22 @@ impl Video {
                     self.channel, self.link, self.title
                 )
             }
-            Notifier::Telegram => {
-                todo!()
+            Notifier::Telegram(_) => {
+                let mut lines = vec![format!("*{}*", notifier::escape_markdown_v2(&self.channel))];
+
+                lines.push(format!(
+                    "[{}]({})",
+                    notifier::escape_markdown_v2(&self.title),
+                    escape_markdown_v2_url(&self.link)
+                ));
+
+                if !time_ago.is_empty() {
+                    lines.push(notifier::escape_markdown_v2(&time_ago));
+                }
+
+                lines.join("\n")
             }
+            Notifier::Rss(_) => format!("{} - {}", self.channel, self.title),
         }
     }
 
@@ -106,6 +187,53 @@ impl Video {
             "just now".to_string()
         }
     }
+
+    /// Renders "Premieres in Xh" / "🔴 Live now" for non-published videos, or
+    /// `None` to fall back to the regular "X hours ago" text.
+    fn format_live_status(&self) -> Option<String> {
+        match self.live_status {
+            LiveStatus::Published => None,
+            LiveStatus::Live => Some("🔴 Live now".to_string()),
+            LiveStatus::Upcoming => {
+                let scheduled_at = self.scheduled_at?;
+                let hours = scheduled_at
+                    .signed_duration_since(Utc::now())
+                    .num_hours()
+                    .max(0);
+                Some(format!("Premieres in {}h", hours))
+            }
+        }
+    }
+}
+
+/// Escapes the characters MarkdownV2 treats as special inside a link URL
+/// (just `)` and `\`, per Telegram's rules for the url part of `[text](url)`).
+fn escape_markdown_v2_url(url: &str) -> String {
+    url.replace('\\', "\\\\").replace(')', "\\)")
+}
+
+/// Scans a YouTube watch page for the `scheduledStartTime` timestamp embedded
+/// in its initial-data JSON, present when the video is a pending premiere or
+/// scheduled livestream.
+fn extract_scheduled_start(html: &str) -> Option<DateTime<Utc>> {
+    let marker = "\"scheduledStartTime\":\"";
+    let start = html.find(marker)? + marker.len();
+    let end = html[start..].find('"')? + start;
+    let seconds: i64 = html[start..end].parse().ok()?;
+    DateTime::from_timestamp(seconds, 0)
+}
+
+/// Trims a video description down to `max_chars` characters for compact
+/// rendering in the Log/markdown notification text, appending `…` when cut short.
+fn truncate_description(description: &str, max_chars: usize) -> String {
+    let trimmed = description.trim();
+
+    if trimmed.chars().count() <= max_chars {
+        trimmed.to_string()
+    } else {
+        let truncated: String = trimmed.chars().take(max_chars).collect();
+        format!("{}…", truncated.trim_end())
+    }
 }
 
 #[cfg(test)]
@@ -138,6 +266,12 @@ mod tests {
             title: "Test Video Title".to_string(),
             link: "https://www.youtube.com/watch?v=test123".to_string(),
             published_at: DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z").unwrap().with_timezone(&chrono::Utc),
+            scheduled_at: None,
+            live_status: LiveStatus::Published,
+            video_id: None,
+            description: None,
+            thumbnail_url: None,
+            view_count: None,
         };
 
         let notifier = Notifier::Log();
@@ -164,6 +298,12 @@ mod tests {
                 title: "Older Video".to_string(),
                 link: "https://www.youtube.com/watch?v=old".to_string(),
                 published_at: DateTime::parse_from_rfc3339("2024-01-01T10:00:00Z").unwrap().with_timezone(&chrono::Utc),
+                scheduled_at: None,
+                live_status: LiveStatus::Published,
+                video_id: None,
+                description: None,
+                thumbnail_url: None,
+                view_count: None,
             },
             Video {
                 channel: "Channel B".to_string(),
@@ -171,6 +311,12 @@ mod tests {
                 title: "Newer Video".to_string(),
                 link: "https://www.youtube.com/watch?v=new".to_string(),
                 published_at: DateTime::parse_from_rfc3339("2024-01-01T14:00:00Z").unwrap().with_timezone(&chrono::Utc),
+                scheduled_at: None,
+                live_status: LiveStatus::Published,
+                video_id: None,
+                description: None,
+                thumbnail_url: None,
+                view_count: None,
             },
             Video {
                 channel: "Channel C".to_string(),
@@ -178,6 +324,12 @@ mod tests {
                 title: "Newest Video".to_string(),
                 link: "https://www.youtube.com/watch?v=newest".to_string(),
                 published_at: DateTime::parse_from_rfc3339("2024-01-01T16:00:00Z").unwrap().with_timezone(&chrono::Utc),
+                scheduled_at: None,
+                live_status: LiveStatus::Published,
+                video_id: None,
+                description: None,
+                thumbnail_url: None,
+                view_count: None,
             },
         ];
 
@@ -189,4 +341,164 @@ mod tests {
         assert_eq!(videos[1].title, "Newer Video");
         assert_eq!(videos[2].title, "Older Video");
     }
+
+    #[test]
+    fn test_notification_text_telegram_escapes_markdown_v2() {
+        use chrono::DateTime;
+        use crate::notifier::{Notifier, TelegramConfig};
+
+        let video = Video {
+            channel: "Test Channel".to_string(),
+            channel_handle: Some("@TestChannel".to_string()),
+            title: "Rust 1.0 - What's New!".to_string(),
+            link: "https://www.youtube.com/watch?v=test123".to_string(),
+            published_at: DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z").unwrap().with_timezone(&chrono::Utc),
+            scheduled_at: None,
+            live_status: LiveStatus::Published,
+            video_id: None,
+            description: None,
+            thumbnail_url: None,
+            view_count: None,
+        };
+
+        let notifier = Notifier::Telegram(TelegramConfig {
+            bot_token: "123:abc".to_string(),
+            chat_id: "456".to_string(),
+            disable_web_page_preview: false,
+        });
+        let result = video.notification_text(&notifier);
+
+        // Channel header is bolded
+        assert!(result.starts_with("*Test Channel*"));
+        // Reserved MarkdownV2 characters in the title are backslash-escaped
+        assert!(result.contains("Rust 1\\.0 \\- What's New\\!"));
+        // The link itself is left usable as a MarkdownV2 link target
+        assert!(result.contains("(https://www.youtube.com/watch?v=test123)"));
+    }
+
+    #[test]
+    fn test_notification_text_upcoming_premiere() {
+        use chrono::DateTime;
+
+        let video = Video {
+            channel: "Test Channel".to_string(),
+            channel_handle: Some("@TestChannel".to_string()),
+            title: "Premiere Video".to_string(),
+            link: "https://www.youtube.com/watch?v=premiere".to_string(),
+            published_at: DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z").unwrap().with_timezone(&chrono::Utc),
+            scheduled_at: Some(Utc::now() + chrono::Duration::hours(3)),
+            live_status: LiveStatus::Upcoming,
+            video_id: None,
+            description: None,
+            thumbnail_url: None,
+            view_count: None,
+        };
+
+        let result = video.notification_text(&Notifier::Log());
+
+        assert!(result.contains("Premieres in"));
+        assert!(!result.contains("just now"));
+    }
+
+    #[test]
+    fn test_notification_text_live_now() {
+        use chrono::DateTime;
+
+        let video = Video {
+            channel: "Test Channel".to_string(),
+            channel_handle: Some("@TestChannel".to_string()),
+            title: "Live Video".to_string(),
+            link: "https://www.youtube.com/watch?v=live".to_string(),
+            published_at: DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z").unwrap().with_timezone(&chrono::Utc),
+            scheduled_at: Some(Utc::now() - chrono::Duration::hours(1)),
+            live_status: LiveStatus::Live,
+            video_id: None,
+            description: None,
+            thumbnail_url: None,
+            view_count: None,
+        };
+
+        let result = video.notification_text(&Notifier::Log());
+
+        assert!(result.contains("🔴 Live now"));
+    }
+
+    #[test]
+    fn test_parse_rss_marks_future_published_entry_as_upcoming() {
+        let scheduled_at = Utc::now() + chrono::Duration::hours(2);
+        let rss_data = format!(
+            r#"<feed>
+                <author><name>Test Channel</name></author>
+                <entry>
+                    <title>Future Premiere</title>
+                    <published>{}</published>
+                    <link href="https://www.youtube.com/watch?v=future"/>
+                </entry>
+                <entry>
+                    <title>Past Upload</title>
+                    <published>2024-01-01T00:00:00Z</published>
+                    <link href="https://www.youtube.com/watch?v=past"/>
+                </entry>
+            </feed>"#,
+            scheduled_at.to_rfc3339()
+        );
+
+        let videos = Video::parse_rss(rss_data, Some("@TestChannel".to_string())).unwrap();
+        let future_video = videos.iter().find(|v| v.link.ends_with("future")).unwrap();
+        let past_video = videos.iter().find(|v| v.link.ends_with("past")).unwrap();
+
+        assert_eq!(future_video.live_status, LiveStatus::Upcoming);
+        assert_eq!(future_video.scheduled_at.unwrap().timestamp(), scheduled_at.timestamp());
+        assert_eq!(past_video.live_status, LiveStatus::Published);
+        assert!(past_video.scheduled_at.is_none());
+    }
+
+    #[test]
+    fn test_extract_scheduled_start_parses_embedded_timestamp() {
+        let html = r#"{"foo":1,"scheduledStartTime":"1893456000","bar":2}"#;
+        let scheduled_at = extract_scheduled_start(html).unwrap();
+        assert_eq!(scheduled_at.timestamp(), 1893456000);
+    }
+
+    #[test]
+    fn test_extract_scheduled_start_missing_marker() {
+        let html = "<html><body>no schedule here</body></html>";
+        assert!(extract_scheduled_start(html).is_none());
+    }
+
+    #[test]
+    fn test_truncate_description_leaves_short_text_untouched() {
+        assert_eq!(truncate_description("A short description.", 140), "A short description.");
+    }
+
+    #[test]
+    fn test_truncate_description_truncates_long_text() {
+        let long_description = "a".repeat(200);
+        let truncated = truncate_description(&long_description, 140);
+        assert_eq!(truncated.chars().count(), 141); // 140 chars + the "…" marker
+        assert!(truncated.ends_with('…'));
+    }
+
+    #[test]
+    fn test_notification_text_log_includes_truncated_description() {
+        use chrono::DateTime;
+
+        let video = Video {
+            channel: "Test Channel".to_string(),
+            channel_handle: Some("@TestChannel".to_string()),
+            title: "Test Video Title".to_string(),
+            link: "https://www.youtube.com/watch?v=test123".to_string(),
+            published_at: DateTime::parse_from_rfc3339("2024-01-01T12:00:00Z").unwrap().with_timezone(&chrono::Utc),
+            scheduled_at: None,
+            live_status: LiveStatus::Published,
+            video_id: Some("test123".to_string()),
+            description: Some("A description of the video.".to_string()),
+            thumbnail_url: Some("https://i.ytimg.com/vi/test123/hqdefault.jpg".to_string()),
+            view_count: Some(42),
+        };
+
+        let result = video.notification_text(&Notifier::Log());
+
+        assert!(result.contains("A description of the video."));
+    }
 }